@@ -0,0 +1,917 @@
+use colored::Colorize;
+use humansize::{file_size_opts as options, FileSize};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::hash::Hasher;
+use std::io::{self, Read, Write};
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Errors surfaced by [`run`]. `main` is the only place that turns these into an
+/// `eprintln!` plus an exit code; everything else propagates with `?`.
+#[derive(Debug)]
+pub enum AppError {
+    Io(io::Error),
+    InvalidArgument(String),
+    OperationFailed(String),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Io(e) => write!(f, "{}", e),
+            AppError::InvalidArgument(message) => write!(f, "{}", message),
+            AppError::OperationFailed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e)
+    }
+}
+
+const PROGRAM_NAME: &str = "rust-explorer";
+
+// Coreutils-style diagnostic: program name, offending path, then a plain-language
+// reason instead of the raw `Display` of an `io::Error`.
+fn describe_io_error(path: &Path, error: &io::Error) -> String {
+    let reason = match error.kind() {
+        io::ErrorKind::PermissionDenied => "permission denied".to_string(),
+        io::ErrorKind::NotFound => "no such file or directory".to_string(),
+        io::ErrorKind::AlreadyExists => "already exists".to_string(),
+        _ => error.to_string(),
+    };
+    format!("{}: {}: {}", PROGRAM_NAME, path.display(), reason)
+}
+
+fn io_error_with_path(path: &Path, error: io::Error) -> AppError {
+    AppError::OperationFailed(describe_io_error(path, &error))
+}
+
+#[derive(Debug)]
+pub struct FileInfo {
+    name: String,
+    path: PathBuf,
+    size: Option<u64>,
+    modified: Option<i64>,
+    is_dir: bool,
+    is_symlink: bool,
+    is_executable: bool,
+    permissions: Option<String>,
+    owner: Option<String>,
+    group: Option<String>,
+}
+
+impl FileInfo {
+    fn new(
+        name: String,
+        path: PathBuf,
+        size: Option<u64>,
+        modified: Option<i64>,
+        is_dir: bool,
+        is_symlink: bool,
+        is_executable: bool,
+        permissions: Option<String>,
+        owner: Option<String>,
+        group: Option<String>,
+    ) -> Self {
+        FileInfo {
+            name,
+            path,
+            size,
+            modified,
+            is_dir,
+            is_symlink,
+            is_executable,
+            permissions,
+            owner,
+            group,
+        }
+    }
+}
+
+// Serializable view of a `FileInfo` for `--output json`/`ndjson`: timestamps become
+// ISO-8601 strings and sizes carry both the raw byte count and a human-readable form.
+#[derive(Serialize)]
+struct FileInfoRecord {
+    name: String,
+    path: PathBuf,
+    size_bytes: Option<u64>,
+    size_human: Option<String>,
+    modified: Option<String>,
+    is_dir: bool,
+    permissions: Option<String>,
+    owner: Option<String>,
+    group: Option<String>,
+}
+
+impl From<&FileInfo> for FileInfoRecord {
+    fn from(file: &FileInfo) -> Self {
+        FileInfoRecord {
+            name: file.name.clone(),
+            path: file.path.clone(),
+            size_bytes: file.size,
+            size_human: file.size.and_then(|size| size.file_size(options::CONVENTIONAL).ok()),
+            modified: file.modified.map(|timestamp| {
+                chrono::NaiveDateTime::from_timestamp(timestamp, 0)
+                    .format("%Y-%m-%dT%H:%M:%SZ")
+                    .to_string()
+            }),
+            is_dir: file.is_dir,
+            permissions: file.permissions.clone(),
+            owner: file.owner.clone(),
+            group: file.group.clone(),
+        }
+    }
+}
+
+fn get_permissions(metadata: &fs::Metadata) -> Option<String> {
+    metadata
+        .permissions()
+        .mode()
+        .to_string()
+        .get(2..)
+        .map(|s| format!("{:03}", usize::from_str_radix(s, 8).unwrap()))
+}
+
+fn get_owner(metadata: &fs::Metadata) -> Option<String> {
+    metadata.uid().to_string().parse::<String>().ok()
+}
+
+fn get_group(metadata: &fs::Metadata) -> Option<String> {
+    metadata.gid().to_string().parse::<String>().ok()
+}
+
+/// Compiled once before a walk and applied during collection. Wraps either a glob
+/// (the default) or, with `--regex`, a compiled regular expression; either is matched
+/// against the entry's bare name and its full path.
+pub enum FileMatcher {
+    Glob(globset::GlobMatcher),
+    Regex(regex::Regex),
+}
+
+impl FileMatcher {
+    pub fn compile(pattern: &str, use_regex: bool, case_insensitive: bool) -> Result<Self, AppError> {
+        if use_regex {
+            let regex = regex::RegexBuilder::new(pattern)
+                .case_insensitive(case_insensitive)
+                .build()
+                .map_err(|e| AppError::InvalidArgument(format!("Invalid regex '{}': {}", pattern, e)))?;
+            Ok(FileMatcher::Regex(regex))
+        } else {
+            let glob = globset::GlobBuilder::new(pattern)
+                .case_insensitive(case_insensitive)
+                .build()
+                .map_err(|e| AppError::InvalidArgument(format!("Invalid glob pattern '{}': {}", pattern, e)))?
+                .compile_matcher();
+            Ok(FileMatcher::Glob(glob))
+        }
+    }
+
+    pub fn matches(&self, name: &str, path: &Path) -> bool {
+        match self {
+            FileMatcher::Glob(matcher) => matcher.is_match(name) || matcher.is_match(path),
+            FileMatcher::Regex(regex) => regex.is_match(name) || regex.is_match(&path.to_string_lossy()),
+        }
+    }
+}
+
+/// Result of a walk: the collected entries plus a diagnostic for every path that was
+/// skipped because it couldn't be read, so callers can report what was missed instead
+/// of silently under-reporting.
+pub struct ExploreResult {
+    pub files: Vec<FileInfo>,
+    pub skipped: Vec<String>,
+}
+
+pub fn explore_directory(
+    dir_path: &str,
+    show_hidden: bool,
+    sort_by: &str,
+    filter: Option<&FileMatcher>,
+    recursive: bool,
+    follow_symlinks: bool,
+) -> ExploreResult {
+    let mut result = collect_entries(Path::new(dir_path), show_hidden, filter, recursive, follow_symlinks);
+
+    match sort_by {
+        "size" => result.files.sort_by(|a, b| a.size.unwrap_or(0).cmp(&b.size.unwrap_or(0))),
+        "date" => result.files.sort_by(|a, b| a.modified.unwrap_or(0).cmp(&b.modified.unwrap_or(0))),
+        "path" => result.files.sort_by(|a, b| a.path.cmp(&b.path)),
+        _ => result.files.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+
+    result
+}
+
+/// Prints every skipped-path diagnostic to stderr. Traversal errors don't fail the
+/// command (the listing itself still succeeded), so this never affects the exit code.
+pub fn print_skipped(skipped: &[String]) {
+    for message in skipped {
+        eprintln!("{}", message);
+    }
+}
+
+// Reads one directory level, then fans out across subdirectories with rayon so sibling
+// trees are walked concurrently. Sorting happens once, at the top of
+// `explore_directory`, instead of being repeated at every depth; the filter is applied
+// here, during collection, so it never retains a full unfiltered tree in memory.
+fn collect_entries(
+    dir_path: &Path,
+    show_hidden: bool,
+    filter: Option<&FileMatcher>,
+    recursive: bool,
+    follow_symlinks: bool,
+) -> ExploreResult {
+    let mut skipped = Vec::new();
+    let mut entries = Vec::new();
+
+    match fs::read_dir(dir_path) {
+        Ok(read_dir) => {
+            for item in read_dir {
+                match item {
+                    Ok(entry) => entries.push(entry),
+                    Err(e) => skipped.push(describe_io_error(dir_path, &e)),
+                }
+            }
+        }
+        Err(e) => {
+            return ExploreResult {
+                files: Vec::new(),
+                skipped: vec![describe_io_error(dir_path, &e)],
+            }
+        }
+    }
+
+    let mut files = Vec::with_capacity(entries.len());
+    let mut subdirs = Vec::new();
+
+    for entry in entries {
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                skipped.push(describe_io_error(&entry.path(), &e));
+                continue;
+            }
+        };
+
+        if !show_hidden && entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        // Symlinks can form cycles, so they're skipped by default.
+        if metadata.file_type().is_symlink() && !follow_symlinks {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = entry.path();
+        let size = metadata.len();
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(std::time::SystemTime::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs() as i64);
+        let permissions = get_permissions(&metadata);
+        let owner = get_owner(&metadata);
+        let group = get_group(&metadata);
+        let is_symlink = metadata.file_type().is_symlink();
+        // `DirEntry::metadata()` doesn't follow symlinks, so a followed symlink to a
+        // directory would otherwise report `is_dir == false` and never get recursed into.
+        let is_dir = if is_symlink && follow_symlinks {
+            fs::metadata(&path).map(|target| target.is_dir()).unwrap_or(false)
+        } else {
+            metadata.is_dir()
+        };
+        let is_executable = metadata.permissions().mode() & 0o111 != 0;
+
+        if recursive && is_dir {
+            subdirs.push(path.clone());
+        }
+
+        let matches_filter = filter.map_or(true, |matcher| matcher.matches(&name, &path));
+
+        let file_info = FileInfo::new(
+            name,
+            path,
+            Some(size),
+            modified,
+            is_dir,
+            is_symlink,
+            is_executable,
+            permissions,
+            owner,
+            group,
+        );
+
+        if matches_filter {
+            files.push(file_info);
+        }
+    }
+
+    if !subdirs.is_empty() {
+        let nested: Vec<ExploreResult> = subdirs
+            .par_iter()
+            .map(|subdir_path| collect_entries(subdir_path, show_hidden, filter, recursive, follow_symlinks))
+            .collect();
+        for nested_result in nested {
+            files.extend(nested_result.files);
+            skipped.extend(nested_result.skipped);
+        }
+    }
+
+    ExploreResult { files, skipped }
+}
+
+// Hashes a file in fixed-size chunks so duplicate detection doesn't have to load
+// large files fully into memory.
+fn hash_file_contents(path: &Path) -> io::Result<u64> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = DefaultHasher::new();
+    let mut buffer = [0u8; 8192];
+
+    loop {
+        let bytes_read = file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.write(&buffer[..bytes_read]);
+    }
+
+    Ok(hasher.finish())
+}
+
+// Compares two same-size files byte-for-byte so a 64-bit hash collision never gets
+// reported (or acted on) as a duplicate.
+fn files_equal(a: &Path, b: &Path) -> io::Result<bool> {
+    let mut file_a = fs::File::open(a)?;
+    let mut file_b = fs::File::open(b)?;
+    let mut buf_a = [0u8; 8192];
+    let mut buf_b = [0u8; 8192];
+
+    loop {
+        let read_a = file_a.read(&mut buf_a)?;
+        let read_b = file_b.read(&mut buf_b)?;
+        if read_a != read_b || buf_a[..read_a] != buf_b[..read_b] {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+// Finds byte-identical files among `files`. First buckets by size (files of different
+// sizes can never match), then hashes only the files sharing a size bucket, then
+// confirms each same-hash group with an actual byte comparison before calling it a
+// duplicate set — the hash alone only rules candidates out, never rules them in.
+pub fn find_duplicate_files(files: &[FileInfo]) -> Vec<(u64, Vec<PathBuf>)> {
+    let mut by_size: HashMap<u64, Vec<&FileInfo>> = HashMap::new();
+    for file in files {
+        if file.is_dir {
+            continue;
+        }
+        if let Some(size) = file.size {
+            by_size.entry(size).or_default().push(file);
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    for (size, candidates) in by_size {
+        if candidates.len() < 2 {
+            continue;
+        }
+
+        let mut by_hash: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for file in candidates {
+            if let Ok(hash) = hash_file_contents(&file.path) {
+                by_hash.entry(hash).or_default().push(file.path.clone());
+            }
+        }
+
+        for paths in by_hash.into_values() {
+            if paths.len() < 2 {
+                continue;
+            }
+
+            let mut confirmed_groups: Vec<Vec<PathBuf>> = Vec::new();
+            for path in paths {
+                let mut placed = false;
+                for group in confirmed_groups.iter_mut() {
+                    if let Ok(true) = files_equal(&group[0], &path) {
+                        group.push(path.clone());
+                        placed = true;
+                        break;
+                    }
+                }
+                if !placed {
+                    confirmed_groups.push(vec![path]);
+                }
+            }
+
+            for group in confirmed_groups {
+                if group.len() >= 2 {
+                    duplicates.push((size, group));
+                }
+            }
+        }
+    }
+
+    duplicates.sort_by(|a, b| b.0.cmp(&a.0));
+    duplicates
+}
+
+fn print_duplicate_groups(groups: &[(u64, Vec<PathBuf>)]) {
+    if groups.is_empty() {
+        println!("No duplicate files found.");
+        return;
+    }
+
+    for (size, paths) in groups {
+        let size_str = size.file_size(options::CONVENTIONAL).unwrap_or_else(|_| size.to_string());
+        println!("Duplicate set ({}, {} files):", size_str, paths.len());
+        for path in paths {
+            println!("  {}", path.display());
+        }
+    }
+}
+
+// Keeps the first path in each group and either hard-links or deletes the rest.
+fn resolve_duplicates(groups: &[(u64, Vec<PathBuf>)], action: &str) {
+    for (_, paths) in groups {
+        let (keep, rest) = match paths.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+
+        for path in rest {
+            let result = match action {
+                "delete" => fs::remove_file(path),
+                "link" => fs::remove_file(path).and_then(|_| fs::hard_link(keep, path)),
+                _ => continue,
+            };
+
+            match result {
+                Ok(_) if action == "link" => println!("Linked {} -> {}", path.display(), keep.display()),
+                Ok(_) => println!("Deleted {}", path.display()),
+                Err(e) => eprintln!("{}", describe_io_error(path, &e)),
+            }
+        }
+    }
+}
+
+fn is_dir_empty(path: &Path) -> bool {
+    fs::read_dir(path).map(|mut entries| entries.next().is_none()).unwrap_or(false)
+}
+
+// Parses a human-readable size such as "500MB" into a raw byte count, the reverse of
+// the `humansize` formatting used elsewhere in this crate.
+pub fn parse_size_to_bytes(input: &str) -> Option<u64> {
+    let input = input.trim();
+    let split_at = input.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(input.len());
+    let (number_part, unit_part) = input.split_at(split_at);
+    let number: f64 = number_part.parse().ok()?;
+
+    // Matches `humansize`'s `CONVENTIONAL` formatting used for listings, which is
+    // 1024-based despite using decimal-style unit names (KB, not KiB).
+    let multiplier = match unit_part.trim().to_uppercase().as_str() {
+        "" | "B" => 1.0,
+        "KB" => 1_024.0,
+        "MB" => 1_024.0f64.powi(2),
+        "GB" => 1_024.0f64.powi(3),
+        "TB" => 1_024.0f64.powi(4),
+        _ => return None,
+    };
+
+    Some((number * multiplier) as u64)
+}
+
+fn find_empty_entries(files: &[FileInfo], include_empty_dirs: bool) -> Vec<PathBuf> {
+    let mut matches: Vec<PathBuf> = files
+        .iter()
+        .filter(|file| !file.is_dir && file.size == Some(0))
+        .map(|file| file.path.clone())
+        .collect();
+
+    if include_empty_dirs {
+        matches.extend(
+            files
+                .iter()
+                .filter(|file| file.is_dir && is_dir_empty(&file.path))
+                .map(|file| file.path.clone()),
+        );
+    }
+
+    matches
+}
+
+fn find_large_files(files: &[FileInfo], threshold_bytes: u64) -> Vec<(PathBuf, u64)> {
+    files
+        .iter()
+        .filter(|file| !file.is_dir && file.size.unwrap_or(0) > threshold_bytes)
+        .map(|file| (file.path.clone(), file.size.unwrap_or(0)))
+        .collect()
+}
+
+fn print_scan_summary(label: &str, checked: usize, matched: &[PathBuf], reclaimable_bytes: u64) {
+    println!("{}:", label);
+    for path in matched {
+        println!("  {}", path.display());
+    }
+
+    let reclaimable = reclaimable_bytes
+        .file_size(options::CONVENTIONAL)
+        .unwrap_or_else(|_| reclaimable_bytes.to_string());
+    println!(
+        "Checked {} entries, matched {}, reclaimable space: {}",
+        checked,
+        matched.len(),
+        reclaimable
+    );
+}
+
+// Prompts for confirmation, then deletes the matched paths and reports how many
+// succeeded versus failed.
+fn confirm_and_delete(paths: &[PathBuf]) {
+    if paths.is_empty() {
+        return;
+    }
+
+    print!("Delete {} matched file(s)? [y/N] ", paths.len());
+    if io::stdout().flush().is_err() {
+        return;
+    }
+
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() || !input.trim().eq_ignore_ascii_case("y") {
+        println!("Aborted.");
+        return;
+    }
+
+    let mut succeeded = 0;
+    let mut failed = 0;
+    for path in paths {
+        match fs::remove_file(path) {
+            Ok(_) => succeeded += 1,
+            Err(e) => {
+                failed += 1;
+                eprintln!("{}", describe_io_error(path, &e));
+            }
+        }
+    }
+
+    println!("Deleted {} file(s), {} failed.", succeeded, failed);
+}
+
+fn perform_file_operation(operation: &str, source: &str, destination: &str) -> Result<(), AppError> {
+    let command = match operation {
+        "copy" => Command::new("cp").arg("-r").arg(source).arg(destination),
+        "move" => Command::new("mv").arg(source).arg(destination),
+        "delete" => Command::new("rm").arg("-r").arg(source),
+        _ => return Err(AppError::InvalidArgument(format!("Unknown operation: {}", operation))),
+    };
+
+    let status = command.status()?;
+    if !status.success() {
+        return Err(AppError::OperationFailed(format!(
+            "{} operation exited with {}",
+            operation, status
+        )));
+    }
+
+    Ok(())
+}
+
+fn view_file(file_path: &str) -> Result<(), AppError> {
+    let command = match std::env::consts::OS {
+        "windows" => Command::new("notepad.exe").arg(file_path),
+        "macos" | "linux" => Command::new("cat").arg(file_path),
+        _ => return Err(AppError::OperationFailed("Unsupported platform for viewing files".to_string())),
+    };
+
+    command.status()?;
+    Ok(())
+}
+
+fn edit_file(file_path: &str) -> Result<(), AppError> {
+    let command = match std::env::consts::OS {
+        "windows" => Command::new("notepad.exe").arg(file_path),
+        "macos" => Command::new("open").arg("-e").arg(file_path),
+        "linux" => Command::new("xdg-open").arg(file_path),
+        _ => return Err(AppError::OperationFailed("Unsupported platform for editing files".to_string())),
+    };
+
+    command.status()?;
+    Ok(())
+}
+
+fn create_directory(directory_path: &str) -> Result<(), AppError> {
+    fs::create_dir(directory_path).map_err(|e| io_error_with_path(Path::new(directory_path), e))?;
+    Ok(())
+}
+
+fn rename_file(old_path: &str, new_name: &str) -> Result<(), AppError> {
+    let new_path = Path::new(old_path).with_file_name(new_name);
+    fs::rename(old_path, &new_path).map_err(|e| io_error_with_path(Path::new(old_path), e))?;
+    Ok(())
+}
+
+fn print_file_content(file_path: &str) -> Result<(), AppError> {
+    let content = fs::read_to_string(file_path).map_err(|e| io_error_with_path(Path::new(file_path), e))?;
+    println!("{}", content);
+    Ok(())
+}
+
+fn write_file_content(file_path: &str, content: &str) -> Result<(), AppError> {
+    fs::write(file_path, content).map_err(|e| io_error_with_path(Path::new(file_path), e))?;
+    println!("Content written to file successfully.");
+    Ok(())
+}
+
+fn search_file_content(file_path: &str, search_query: &str) -> Result<(), AppError> {
+    let content = fs::read_to_string(file_path).map_err(|e| io_error_with_path(Path::new(file_path), e))?;
+    if content.contains(search_query) {
+        println!("Search query found in the file.");
+    } else {
+        println!("Search query not found in the file.");
+    }
+    Ok(())
+}
+
+fn execute_shell_command(command: &str) -> Result<(), AppError> {
+    let status = Command::new("sh").arg("-c").arg(command).status()?;
+    if !status.success() {
+        return Err(AppError::OperationFailed(format!("Shell command exited with {}", status)));
+    }
+    Ok(())
+}
+
+// Colors parsed out of `LS_COLORS`, keyed the same way `ls`/`dircolors` key them:
+// `di` (directory), `ln` (symlink), `ex` (executable), `fi` (regular file), and any
+// number of `*.ext` glob entries.
+#[derive(Default)]
+struct LsColors {
+    directory: Option<String>,
+    symlink: Option<String>,
+    executable: Option<String>,
+    file: Option<String>,
+    by_extension: HashMap<String, String>,
+}
+
+fn parse_ls_colors(raw: &str) -> LsColors {
+    let mut colors = LsColors::default();
+
+    for entry in raw.split(':') {
+        let mut parts = entry.splitn(2, '=');
+        let key = match parts.next() {
+            Some(key) if !key.is_empty() => key,
+            _ => continue,
+        };
+        let code = match parts.next() {
+            Some(code) => code.to_string(),
+            None => continue,
+        };
+
+        if let Some(extension) = key.strip_prefix("*.") {
+            colors.by_extension.insert(extension.to_string(), code);
+        } else {
+            match key {
+                "di" => colors.directory = Some(code),
+                "ln" => colors.symlink = Some(code),
+                "ex" => colors.executable = Some(code),
+                "fi" => colors.file = Some(code),
+                _ => {}
+            }
+        }
+    }
+
+    colors
+}
+
+// Resolves the SGR code for a file by precedence: directory, then symlink, then
+// executable, then the longest matching `*.extension` entry, then the default file
+// color.
+fn resolve_ls_color<'a>(file: &FileInfo, colors: &'a LsColors) -> Option<&'a str> {
+    if file.is_dir {
+        return colors.directory.as_deref();
+    }
+    if file.is_symlink {
+        return colors.symlink.as_deref();
+    }
+    if file.is_executable && colors.executable.is_some() {
+        return colors.executable.as_deref();
+    }
+
+    colors
+        .by_extension
+        .iter()
+        .filter(|(extension, _)| file.name.ends_with(&format!(".{}", extension)))
+        .max_by_key(|(extension, _)| extension.len())
+        .map(|(_, code)| code.as_str())
+        .or(colors.file.as_deref())
+}
+
+fn colorize(text: &str, sgr_code: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", sgr_code, text)
+}
+
+fn default_display_name(file: &FileInfo) -> String {
+    if file.is_dir {
+        file.name.blue().to_string()
+    } else {
+        file.name.white().to_string()
+    }
+}
+
+fn print_table(files: &[FileInfo]) {
+    let ls_colors = std::env::var("LS_COLORS").ok().map(|raw| parse_ls_colors(&raw));
+
+    for file in files {
+        let display_name = match &ls_colors {
+            Some(colors) => match resolve_ls_color(file, colors) {
+                Some(code) => colorize(&file.name, code),
+                None => default_display_name(file),
+            },
+            None => default_display_name(file),
+        };
+
+        let size = if let Some(size) = file.size {
+            format!("{}", size.file_size(options::CONVENTIONAL).unwrap())
+        } else {
+            String::from("N/A")
+        };
+
+        let modified = if let Some(modified) = file.modified {
+            chrono::NaiveDateTime::from_timestamp(modified, 0).to_string()
+        } else {
+            String::from("N/A")
+        };
+
+        let permissions = if let Some(permissions) = &file.permissions {
+            permissions.to_string()
+        } else {
+            String::from("N/A")
+        };
+
+        let owner = if let Some(owner) = &file.owner {
+            owner.to_string()
+        } else {
+            String::from("N/A")
+        };
+
+        let group = if let Some(group) = &file.group {
+            group.to_string()
+        } else {
+            String::from("N/A")
+        };
+
+        println!(
+            "{:<30} {:<15} {:<20} {:<20} {:<20} {:<20} {}",
+            display_name, size, modified, permissions, owner, group
+        );
+    }
+}
+
+fn print_json(files: &[FileInfo]) -> Result<(), AppError> {
+    let records: Vec<FileInfoRecord> = files.iter().map(FileInfoRecord::from).collect();
+    let json = serde_json::to_string_pretty(&records)
+        .map_err(|e| AppError::OperationFailed(format!("Failed to serialize listing: {}", e)))?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn print_ndjson(files: &[FileInfo]) -> Result<(), AppError> {
+    for file in files {
+        let json = serde_json::to_string(&FileInfoRecord::from(file))
+            .map_err(|e| AppError::OperationFailed(format!("Failed to serialize entry: {}", e)))?;
+        println!("{}", json);
+    }
+    Ok(())
+}
+
+fn preview_file(file_path: &str, lines: usize) -> Result<(), AppError> {
+    let content = fs::read_to_string(file_path).map_err(|e| io_error_with_path(Path::new(file_path), e))?;
+    for (i, line) in content.lines().take(lines).enumerate() {
+        println!("{}: {}", i + 1, line);
+    }
+    Ok(())
+}
+
+/// Core entry point: dispatches on the already-parsed CLI arguments and returns any
+/// failure instead of exiting, so the binary and integration tests share one path.
+pub fn run(matches: &clap::ArgMatches) -> Result<(), AppError> {
+    if let Some(operation) = matches.value_of("operation") {
+        if let (Some(source), Some(destination)) = (matches.value_of("source"), matches.value_of("destination")) {
+            return perform_file_operation(operation, source, destination);
+        }
+    }
+
+    if let Some(file_path) = matches.value_of("view") {
+        return view_file(file_path);
+    }
+
+    if let Some(file_path) = matches.value_of("edit") {
+        return edit_file(file_path);
+    }
+
+    if let Some(directory_path) = matches.value_of("create_dir") {
+        return create_directory(directory_path);
+    }
+
+    if let (Some(file_path), Some(new_name)) = (matches.value_of("rename"), matches.value_of("directory")) {
+        return rename_file(file_path, new_name);
+    }
+
+    if let Some(file_path) = matches.value_of("content") {
+        return print_file_content(file_path);
+    }
+
+    if let (Some(file_path), Some(content)) = (matches.value_of("write_content"), matches.value_of("content")) {
+        return write_file_content(file_path, content);
+    }
+
+    if let (Some(file_path), Some(search_query)) =
+        (matches.value_of("search_content"), matches.value_of("search_content"))
+    {
+        return search_file_content(file_path, search_query);
+    }
+
+    if let Some(shell_command) = matches.value_of("shell_command") {
+        return execute_shell_command(shell_command);
+    }
+
+    let dir_path = matches.value_of("directory").unwrap_or(".");
+    let show_hidden = matches.is_present("hidden");
+    let sort_by = matches.value_of("sort").unwrap_or("name");
+    let recursive = matches.is_present("recursive");
+    let follow_symlinks = matches.is_present("follow_symlinks");
+
+    let matcher = match matches.value_of("filter") {
+        Some(pattern) => Some(FileMatcher::compile(
+            pattern,
+            matches.is_present("regex"),
+            matches.is_present("case_insensitive"),
+        )?),
+        None => None,
+    };
+    let filter = matcher.as_ref();
+
+    if matches.is_present("duplicates") {
+        let result = explore_directory(dir_path, show_hidden, sort_by, filter, true, follow_symlinks);
+        let groups = find_duplicate_files(&result.files);
+        print_duplicate_groups(&groups);
+        if let Some(action) = matches.value_of("dedupe_action") {
+            resolve_duplicates(&groups, action);
+        }
+        print_skipped(&result.skipped);
+        return Ok(());
+    }
+
+    if matches.is_present("empty") {
+        let result = explore_directory(dir_path, show_hidden, sort_by, filter, true, follow_symlinks);
+        let include_empty_dirs = matches.is_present("include_empty_dirs");
+        let matched = find_empty_entries(&result.files, include_empty_dirs);
+        print_scan_summary("Empty files", result.files.len(), &matched, 0);
+        if matches.is_present("delete") {
+            confirm_and_delete(&matched);
+        }
+        print_skipped(&result.skipped);
+        return Ok(());
+    }
+
+    if let Some(threshold) = matches.value_of("bigger_than") {
+        let threshold_bytes = parse_size_to_bytes(threshold)
+            .ok_or_else(|| AppError::InvalidArgument(format!("Invalid size '{}', expected e.g. 500MB", threshold)))?;
+
+        let result = explore_directory(dir_path, show_hidden, sort_by, filter, true, follow_symlinks);
+        let large_files = find_large_files(&result.files, threshold_bytes);
+        let reclaimable_bytes: u64 = large_files.iter().map(|(_, size)| size).sum();
+        let matched: Vec<PathBuf> = large_files.into_iter().map(|(path, _)| path).collect();
+        print_scan_summary("Files bigger than threshold", result.files.len(), &matched, reclaimable_bytes);
+        if matches.is_present("delete") {
+            confirm_and_delete(&matched);
+        }
+        print_skipped(&result.skipped);
+        return Ok(());
+    }
+
+    let result = explore_directory(dir_path, show_hidden, sort_by, filter, recursive, follow_symlinks);
+
+    match matches.value_of("output").unwrap_or("table") {
+        "json" => print_json(&result.files)?,
+        "ndjson" => print_ndjson(&result.files)?,
+        _ => print_table(&result.files),
+    }
+
+    print_skipped(&result.skipped);
+    Ok(())
+}
@@ -1,216 +1,5 @@
 use clap::{App, Arg};
-use colored::Colorize;
-use humansize::{file_size_opts as options, FileSize};
-use std::fs;
-use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::io::{self, Write};
-
-#[derive(Debug)]
-struct FileInfo {
-    name: String,
-    path: PathBuf,
-    size: Option<u64>,
-    modified: Option<i64>,
-    is_dir: bool,
-    permissions: Option<String>,
-    owner: Option<String>,
-    group: Option<String>,
-}
-
-impl FileInfo {
-    fn new(
-        name: String,
-        path: PathBuf,
-        size: Option<u64>,
-        modified: Option<i64>,
-        is_dir: bool,
-        permissions: Option<String>,
-        owner: Option<String>,
-        group: Option<String>,
-    ) -> Self {
-        FileInfo {
-            name,
-            path,
-            size,
-            modified,
-            is_dir,
-            permissions,
-            owner,
-            group,
-        }
-    }
-}
-
-fn handle_error(message: &str) {
-    eprintln!("Error: {}", message);
-    std::process::exit(1);
-}
-
-fn get_permissions(metadata: &fs::Metadata) -> Option<String> {
-    metadata
-        .permissions()
-        .mode()
-        .to_string()
-        .get(2..)
-        .map(|s| format!("{:03}", usize::from_str_radix(s, 8).unwrap()))
-}
-
-fn get_owner(metadata: &fs::Metadata) -> Option<String> {
-    metadata.uid().to_string().parse::<String>().ok()
-}
-
-fn get_group(metadata: &fs::Metadata) -> Option<String> {
-    metadata.gid().to_string().parse::<String>().ok()
-}
-
-fn explore_directory(
-    dir_path: &str,
-    show_hidden: bool,
-    sort_by: &str,
-    filter_by: Option<&str>,
-    recursive: bool,
-) -> Vec<FileInfo> {
-    let mut files: Vec<FileInfo> = Vec::new();
-
-    if let Ok(entries) = fs::read_dir(&dir_path) {
-        for entry in entries.filter_map(|entry| entry.ok()) {
-            let metadata = match entry.metadata() {
-                Ok(metadata) => metadata,
-                Err(_) => continue,
-            };
-
-            if !show_hidden && entry.file_name().to_string_lossy().starts_with('.') {
-                continue;
-            }
-
-            let name = entry.file_name().to_string_lossy().to_string();
-            let path = entry.path();
-            let size = metadata.len();
-            let modified =
-                metadata.modified().ok()?.duration_since(std::time::SystemTime::UNIX_EPOCH).ok()?.as_secs() as i64;
-            let permissions = get_permissions(&metadata);
-            let owner = get_owner(&metadata);
-            let group = get_group(&metadata);
-            let is_dir = metadata.is_dir();
-
-            files.push(FileInfo::new(
-                name,
-                path,
-                Some(size),
-                Some(modified),
-                is_dir,
-                permissions,
-                owner,
-                group,
-            ));
-
-            if recursive && is_dir {
-                let subdir_path = Path::new(dir_path).join(entry.file_name());
-                let subdir_files =
-                    explore_directory(&subdir_path.to_string_lossy(), show_hidden, sort_by, filter_by, recursive);
-                files.extend(subdir_files);
-            }
-        }
-    }
-
-    match sort_by {
-        "size" => files.sort_by(|a, b| a.size.unwrap_or(0).cmp(&b.size.unwrap_or(0))),
-        "date" => files.sort_by(|a, b| a.modified.unwrap_or(0).cmp(&b.modified.unwrap_or(0))),
-        "path" => files.sort_by(|a, b| a.path.cmp(&b.path)),
-        _ => files.sort_by(|a, b| a.name.cmp(&b.name)),
-    }
-
-    if let Some(extension) = filter_by {
-        files.retain(|file| file.name.ends_with(extension));
-    }
-
-    files
-}
-
-fn perform_file_operation(operation: &str, source: &str, destination: &str) {
-    let command = match operation {
-        "copy" => Command::new("cp").arg("-r").arg(source).arg(destination),
-        "move" => Command::new("mv").arg(source).arg(destination),
-        "delete" => Command::new("rm").arg("-r").arg(source),
-        _ => return,
-    };
-
-    if let Err(e) = command.status() {
-        handle_error(&format!("Failed to perform file operation: {}", e));
-    }
-}
-
-fn view_file(file_path: &str) {
-    let command = match std::env::consts::OS {
-        "windows" => Command::new("notepad.exe").arg(file_path),
-        "macos" | "linux" => Command::new("cat").arg(file_path),
-        _ => return,
-    };
-
-    if let Err(e) = command.status() {
-        handle_error(&format!("Failed to view file: {}", e));
-    }
-}
-
-fn edit_file(file_path: &str) {
-    let command = match std::env::consts::OS {
-        "windows" => Command::new("notepad.exe").arg(file_path),
-        "macos" => Command::new("open").arg("-e").arg(file_path),
-        "linux" => Command::new("xdg-open").arg(file_path),
-        _ => return,
-    };
-
-    if let Err(e) = command.status() {
-        handle_error(&format!("Failed to edit file: {}", e));
-    }
-}
-
-fn create_directory(directory_path: &str) {
-    if let Err(e) = fs::create_dir(directory_path) {
-        handle_error(&format!("Failed to create directory: {}", e));
-    }
-}
-
-fn rename_file(old_path: &str, new_name: &str) {
-    let new_path = Path::new(old_path).with_file_name(new_name);
-    if let Err(e) = fs::rename(old_path, &new_path) {
-        handle_error(&format!("Failed to rename file: {}", e));
-    }
-}
-
-fn print_file_content(file_path: &str) {
-    match fs::read_to_string(file_path) {
-        Ok(content) => println!("{}", content),
-        Err(e) => handle_error(&format!("Failed to read file content: {}", e)),
-    }
-}
-
-fn write_file_content(file_path: &str, content: &str) {
-    match fs::write(file_path, content) {
-        Ok(_) => println!("Content written to file successfully."),
-        Err(e) => handle_error(&format!("Failed to write to file: {}", e)),
-    }
-}
-
-fn search_file_content(file_path: &str, search_query: &str) {
-    match fs::read_to_string(file_path) {
-        Ok(content) => {
-            if content.contains(search_query) {
-                println!("Search query found in the file.");
-            } else {
-                println!("Search query not found in the file.");
-            }
-        }
-        Err(e) => handle_error(&format!("Failed to read file content: {}", e)),
-    }
-}
-
-fn execute_shell_command(command: &str) {
-    if let Err(e) = Command::new("sh").arg("-c").arg(command).status() {
-        handle_error(&format!("Failed to execute shell command: {}", e));
-    }
-}
+use file_explorer::run;
 
 fn main() {
     let matches = App::new("Rust File Explorer")
@@ -238,9 +27,21 @@ fn main() {
                 .short("f")
                 .long("filter")
                 .value_name("FILTER")
-                .help("Filter files by extension")
+                .help("Filter files by a glob pattern (or a regex with --regex), matched against name or path")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("regex")
+                .long("regex")
+                .help("Treat --filter as a regular expression instead of a glob")
+                .requires("filter"),
+        )
+        .arg(
+            Arg::with_name("case_insensitive")
+                .long("case-insensitive")
+                .help("Match --filter case-insensitively")
+                .requires("filter"),
+        )
         .arg(
             Arg::with_name("hidden")
                 .short("h")
@@ -253,6 +54,12 @@ fn main() {
                 .long("recursive")
                 .help("Explore directories recursively"),
         )
+        .arg(
+            Arg::with_name("follow_symlinks")
+                .short("L")
+                .long("follow-symlinks")
+                .help("Follow symlinks instead of skipping them during traversal"),
+        )
         .arg(
             Arg::with_name("operation")
                 .short("o")
@@ -341,115 +148,53 @@ fn main() {
                 .help("Execute a shell command")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("duplicates")
+                .long("duplicates")
+                .help("Report groups of byte-identical files under the explored directory"),
+        )
+        .arg(
+            Arg::with_name("dedupe_action")
+                .long("dedupe-action")
+                .value_name("ACTION")
+                .help("Resolve reported duplicates: 'link' to hard-link or 'delete' to remove all but one")
+                .takes_value(true)
+                .requires("duplicates"),
+        )
+        .arg(
+            Arg::with_name("empty")
+                .long("empty")
+                .help("List every regular file with size 0"),
+        )
+        .arg(
+            Arg::with_name("include_empty_dirs")
+                .long("include-empty-dirs")
+                .help("Also list directories with no entries")
+                .requires("empty"),
+        )
+        .arg(
+            Arg::with_name("bigger_than")
+                .long("bigger-than")
+                .value_name("SIZE")
+                .help("List files bigger than a human-readable size, e.g. 500MB")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("delete")
+                .long("delete")
+                .help("Prompt to delete the files matched by --empty or --bigger-than"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .value_name("FORMAT")
+                .help("Output format for the listing: table (default), json, or ndjson")
+                .takes_value(true),
+        )
         .get_matches();
 
-    if let Some(operation) = matches.value_of("operation") {
-        if let (Some(source), Some(destination)) = (matches.value_of("source"), matches.value_of("destination")) {
-            perform_file_operation(operation, source, destination);
-            return;
-        }
-    }
-
-    if let Some(file_path) = matches.value_of("view") {
-        view_file(file_path);
-        return;
-    }
-
-    if let Some(file_path) = matches.value_of("edit") {
-        edit_file(file_path);
-        return;
-    }
-
-    if let Some(directory_path) = matches.value_of("create_dir") {
-        create_directory(directory_path);
-        return;
-    }
-
-    if let (Some(file_path), Some(new_name)) = (matches.value_of("rename"), matches.value_of("directory")) {
-        rename_file(file_path, new_name);
-        return;
-    }
-
-    if let Some(file_path) = matches.value_of("content") {
-        print_file_content(file_path);
-        return;
-    }
-
-    if let (Some(file_path), Some(content)) = (matches.value_of("write_content"), matches.value_of("content")) {
-        write_file_content(file_path, content);
-        return;
-    }
-
-    if let (Some(file_path), Some(search_query)) =
-        (matches.value_of("search_content"), matches.value_of("search_content"))
-    {
-        search_file_content(file_path, search_query);
-        return;
-    }
-
-    if let Some(shell_command) = matches.value_of("shell_command") {
-        execute_shell_command(shell_command);
-        return;
-    }
-
-    let dir_path = matches.value_of("directory").unwrap_or(".");
-    let show_hidden = matches.is_present("hidden");
-    let sort_by = matches.value_of("sort").unwrap_or("name");
-    let filter_by = matches.value_of("filter");
-    let recursive = matches.is_present("recursive");
-
-    let files = explore_directory(dir_path, show_hidden, sort_by, filter_by, recursive);
-
-    for file in files {
-        let display_name = if file.is_dir {
-            file.name.blue().to_string()
-        } else {
-            file.name.white().to_string()
-        };
-
-        let size = if let Some(size) = file.size {
-            format!("{}", size.file_size(options::CONVENTIONAL).unwrap())
-        } else {
-            String::from("N/A")
-        };
-
-        let modified = if let Some(modified) = file.modified {
-            chrono::NaiveDateTime::from_timestamp(modified, 0).to_string()
-        } else {
-            String::from("N/A")
-        };
-
-        let permissions = if let Some(permissions) = &file.permissions {
-            permissions.to_string()
-        } else {
-            String::from("N/A")
-        };
-
-        let owner = if let Some(owner) = &file.owner {
-            owner.to_string()
-        } else {
-            String::from("N/A")
-        };
-
-        let group = if let Some(group) = &file.group {
-            group.to_string()
-        } else {
-            String::from("N/A")
-        };
-
-        println!(
-            "{:<30} {:<15} {:<20} {:<20} {:<20} {:<20} {}",
-            display_name, size, modified, permissions, owner, group
-        );
-    }
-}
-
-fn preview_file(file_path: &str, lines: usize) {
-    if let Ok(content) = fs::read_to_string(file_path) {
-        for (i, line) in content.lines().take(lines).enumerate() {
-            println!("{}: {}", i + 1, line);
-        }
-    } else {
-        eprintln!("Error: Could not preview the file.");
+    if let Err(err) = run(&matches) {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,76 @@
+use file_explorer::{explore_directory, find_duplicate_files, parse_size_to_bytes, run, FileMatcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn make_temp_dir(label: &str) -> PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("rust_explorer_it_{}_{}", std::process::id(), label));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn parse_size_to_bytes_is_1024_based() {
+    assert_eq!(parse_size_to_bytes("1B"), Some(1));
+    assert_eq!(parse_size_to_bytes("1KB"), Some(1024));
+    assert_eq!(parse_size_to_bytes("1MB"), Some(1024 * 1024));
+    assert_eq!(parse_size_to_bytes("bogus"), None);
+}
+
+#[test]
+fn file_matcher_glob_matches_name_or_path() {
+    let matcher = FileMatcher::compile("*.rs", false, false).unwrap();
+    assert!(matcher.matches("main.rs", Path::new("/tmp/main.rs")));
+    assert!(!matcher.matches("main.txt", Path::new("/tmp/main.txt")));
+}
+
+#[test]
+fn file_matcher_regex_is_case_insensitive_when_requested() {
+    let matcher = FileMatcher::compile("^TEST_", true, true).unwrap();
+    assert!(matcher.matches("test_file.rs", Path::new("/tmp/test_file.rs")));
+}
+
+#[test]
+fn duplicate_detection_requires_identical_bytes_not_just_matching_size() {
+    let dir = make_temp_dir("duplicates");
+    fs::write(dir.join("a.txt"), b"hello").unwrap();
+    fs::write(dir.join("b.txt"), b"hello").unwrap();
+    fs::write(dir.join("c.txt"), b"world").unwrap(); // same size as a/b, different bytes
+
+    let result = explore_directory(dir.to_str().unwrap(), false, "name", None, false, false);
+    let groups = find_duplicate_files(&result.files);
+
+    assert_eq!(groups.len(), 1);
+    let (_, paths) = &groups[0];
+    assert_eq!(paths.len(), 2);
+    assert!(paths.iter().any(|p| p.ends_with("a.txt")));
+    assert!(paths.iter().any(|p| p.ends_with("b.txt")));
+    assert!(!paths.iter().any(|p| p.ends_with("c.txt")));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn run_lists_a_directory_and_returns_ok_instead_of_exiting() {
+    let dir = make_temp_dir("run_listing");
+    fs::write(dir.join("file.txt"), b"contents").unwrap();
+
+    let matches = clap::App::new("test")
+        .arg(clap::Arg::with_name("directory").long("dir").takes_value(true))
+        .arg(clap::Arg::with_name("output").long("output").takes_value(true))
+        .get_matches_from(vec!["test", "--dir", dir.to_str().unwrap(), "--output", "ndjson"]);
+
+    assert!(run(&matches).is_ok());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn run_reports_an_invalid_bigger_than_size_as_an_error() {
+    let matches = clap::App::new("test")
+        .arg(clap::Arg::with_name("bigger_than").long("bigger-than").takes_value(true))
+        .get_matches_from(vec!["test", "--bigger-than", "not-a-size"]);
+
+    assert!(run(&matches).is_err());
+}